@@ -3,6 +3,7 @@
 #[ink::contract]
 mod votaciones {
     use ink::prelude::string::String;
+    use ink::prelude::vec::Vec;
     use ink::storage::Mapping;
     use scale::{Decode, Encode};
     #[cfg(feature = "std")]
@@ -10,6 +11,10 @@ mod votaciones {
     #[cfg(feature = "std")]
     use scale_info::TypeInfo;
 
+    /// Upper bound on how many proposals `get_proposals` returns in a single call, regardless of
+    /// the requested `limit`, to keep the read within a predictable weight envelope.
+    const MAX_PAGE: u32 = 50;
+
 
 
     #[derive(Encode, Decode, Debug, PartialEq, Eq)]
@@ -17,14 +22,36 @@ mod votaciones {
     pub enum Error {
         NotOwner,
         ProposalNotFound,
-        AlreadyVoted,
         MaxProposalsReached,
         Overflow,
+        VotingClosed,
+        DurationTooShort,
+        ProposalClosed,
+        InsufficientVotingPower,
+        NoChange,
+        VoteLocked,
+        VotingStillOpen,
     }
 
 
 
 
+#[derive(Encode, Decode, Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(TypeInfo))]
+pub enum VoteChoice {
+    For,
+    Against,
+    Abstain,
+}
+
+#[derive(Encode, Decode, Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(TypeInfo))]
+pub enum ProposalStatus {
+    Active,
+    Passed,
+    Rejected,
+}
+
 #[derive(Encode, Decode, Clone, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "std", derive(TypeInfo, StorageLayout))]
 pub struct Proposal {
@@ -32,6 +59,11 @@ pub struct Proposal {
     title: String,
     votes_for: u32,
     votes_against: u32,
+    votes_abstain: u32,
+    start_time: u64,
+    end_time: u64,
+    status: ProposalStatus,
+    lockout_ms: u64,
 }
 
 
@@ -41,6 +73,8 @@ pub struct Proposal {
         #[ink(topic)]
         id: u32,
         title: String,
+        start_time: u64,
+        end_time: u64,
     }
     
     #[ink(event)]
@@ -49,7 +83,24 @@ pub struct Proposal {
         proposalid: u32,
         #[ink(topic)]
         voter: AccountId,
-        state: bool,
+        choice: VoteChoice,
+    }
+
+    #[ink(event)]
+    pub struct ProposalFinalized {
+        #[ink(topic)]
+        id: u32,
+        status: ProposalStatus,
+    }
+
+    #[ink(event)]
+    pub struct VoteChanged {
+        #[ink(topic)]
+        proposalid: u32,
+        #[ink(topic)]
+        voter: AccountId,
+        old_choice: VoteChoice,
+        new_choice: VoteChoice,
     }
 
 
@@ -57,8 +108,45 @@ pub struct Proposal {
     pub struct Votaciones {
         owner: AccountId,
         proposals: Mapping<u32, Proposal>,
-        has_voted: Mapping<(u32, AccountId), bool>,
+        has_voted: Mapping<(u32, AccountId), (VoteChoice, u32)>,
         next_proposal_id: u32,
+        min_duration: u64,
+        quorum: u32,
+        approval_numerator: u32,
+        approval_denominator: u32,
+        voting_power: Mapping<AccountId, u32>,
+        min_vote_power: u32,
+    }
+    /// Adds `weight` to the tally matching `choice`.
+    fn add_vote_weight(proposal: &mut Proposal, choice: VoteChoice, weight: u32) -> Result<(), Error> {
+        match choice {
+            VoteChoice::For => {
+                proposal.votes_for = proposal.votes_for.checked_add(weight).ok_or(Error::Overflow)?;
+            }
+            VoteChoice::Against => {
+                proposal.votes_against = proposal.votes_against.checked_add(weight).ok_or(Error::Overflow)?;
+            }
+            VoteChoice::Abstain => {
+                proposal.votes_abstain = proposal.votes_abstain.checked_add(weight).ok_or(Error::Overflow)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Removes `weight` from the tally matching `choice`, undoing a previously counted vote.
+    fn remove_vote_weight(proposal: &mut Proposal, choice: VoteChoice, weight: u32) -> Result<(), Error> {
+        match choice {
+            VoteChoice::For => {
+                proposal.votes_for = proposal.votes_for.checked_sub(weight).ok_or(Error::Overflow)?;
+            }
+            VoteChoice::Against => {
+                proposal.votes_against = proposal.votes_against.checked_sub(weight).ok_or(Error::Overflow)?;
+            }
+            VoteChoice::Abstain => {
+                proposal.votes_abstain = proposal.votes_abstain.checked_sub(weight).ok_or(Error::Overflow)?;
+            }
+        }
+        Ok(())
     }
 
     impl Votaciones {
@@ -70,82 +158,249 @@ pub struct Proposal {
                 proposals: Mapping::default(),
                 has_voted: Mapping::default(),
                 next_proposal_id: 0,
+                min_duration: 0,
+                quorum: 0,
+                approval_numerator: 1,
+                approval_denominator: 2,
+                voting_power: Mapping::default(),
+                min_vote_power: 0,
             }
 }
 
+        /// Sets the minimum allowed voting duration in milliseconds. Only the owner can call this.
+        #[ink(message)]
+        pub fn set_min_duration(&mut self, min_duration: u64) -> Result<(), Error> {
+            let caller = self.env().caller();
+            if caller != self.owner {
+                return Err(Error::NotOwner);
+            }
+            self.min_duration = min_duration;
+            Ok(())
+        }
+
+        /// Sets the minimum total vote count (quorum) required for a proposal to pass. Only the owner can call this.
+        #[ink(message)]
+        pub fn set_quorum(&mut self, quorum: u32) -> Result<(), Error> {
+            let caller = self.env().caller();
+            if caller != self.owner {
+                return Err(Error::NotOwner);
+            }
+            self.quorum = quorum;
+            Ok(())
+        }
+
+        /// Sets the approval ratio (`approval_numerator` / `approval_denominator`) a proposal's
+        /// for-votes must exceed, relative to for + against, to pass. Only the owner can call this.
+        #[ink(message)]
+        pub fn set_approval_ratio(&mut self, numerator: u32, denominator: u32) -> Result<(), Error> {
+            let caller = self.env().caller();
+            if caller != self.owner {
+                return Err(Error::NotOwner);
+            }
+            self.approval_numerator = numerator;
+            self.approval_denominator = denominator;
+            Ok(())
+        }
+
+        /// Sets the voting weight for `account`. Only the owner can call this. Accounts with no
+        /// weight set default to a weight of 1 in `vote`.
+        #[ink(message)]
+        pub fn set_voting_power(&mut self, account: AccountId, weight: u32) -> Result<(), Error> {
+            let caller = self.env().caller();
+            if caller != self.owner {
+                return Err(Error::NotOwner);
+            }
+            self.voting_power.insert(account, &weight);
+            Ok(())
+        }
+
+        /// Sets the minimum voting weight an account must hold to cast a vote. Only the owner can call this.
+        #[ink(message)]
+        pub fn set_min_vote_power(&mut self, min_vote_power: u32) -> Result<(), Error> {
+            let caller = self.env().caller();
+            if caller != self.owner {
+                return Err(Error::NotOwner);
+            }
+            self.min_vote_power = min_vote_power;
+            Ok(())
+        }
+
 
         /// Creates a new proposal. Only the owner can create proposals.
         /// The next proposal Id is incremented after using it on the new proposal.
         /// The new proposal is inserted in the mapping of the contract
 
        #[ink(message)]
-        pub fn create_proposal(&mut self, title: String) -> Result<u32, Error> {
+        pub fn create_proposal(
+            &mut self,
+            title: String,
+            duration_ms: u64,
+            lockout_ms: u64,
+        ) -> Result<u32, Error> {
             let caller = self.env().caller();
             if caller != self.owner {
                 return Err(Error::NotOwner);
             }
 
+            if duration_ms < self.min_duration {
+                return Err(Error::DurationTooShort);
+            }
+
             let id = self.next_proposal_id;
             self.next_proposal_id = self
                 .next_proposal_id
                 .checked_add(1)
                 .ok_or(Error::MaxProposalsReached)?;
-           
+
+            let start_time = self.env().block_timestamp();
+            let end_time = start_time.checked_add(duration_ms).ok_or(Error::Overflow)?;
 
             let proposal = Proposal {
                 id,
                 title: title.clone(),
                 votes_for: 0,
                 votes_against: 0,
+                votes_abstain: 0,
+                start_time,
+                end_time,
+                status: ProposalStatus::Active,
+                lockout_ms,
             };
 
             self.proposals.insert(id, &proposal);
-            self.env().emit_event(ProposalCreated { id, title });
+            self.env().emit_event(ProposalCreated { id, title, start_time, end_time });
             Ok(id)
         }
 
-        /// Casts a vote on a proposal identified by `proposalid`.
-        /// The vote is reverted if the address has already voted on that proposal id.
+        /// Casts a vote on a proposal identified by `proposalid`. Calling it again before the
+        /// deadline changes the caller's vote (see `VoteChanged`), unless the proposal is within
+        /// its `lockout_ms` window, in which case changes are frozen.
         #[ink(message)]
-    pub fn vote(&mut self, proposalid: u32, state: bool) -> Result<(), Error> {
+    pub fn vote(&mut self, proposalid: u32, choice: VoteChoice) -> Result<(), Error> {
         let caller = self.env().caller();
 
         // existe proposal?
         let mut proposal = self.proposals.get(proposalid).ok_or(Error::ProposalNotFound)?;
 
-        // ya votó?
-        let key = (proposalid, caller);
-        if self.has_voted.get(key).unwrap_or(false) {
-            return Err(Error::AlreadyVoted);
+        // ya se finalizó?
+        if proposal.status != ProposalStatus::Active {
+            return Err(Error::ProposalClosed);
         }
 
-        // contar voto
-        if state {
-            proposal.votes_for = proposal
-                .votes_for
-                .checked_add(1)
-                .ok_or(Error::Overflow)?;
-        } else {
-            proposal.votes_against = proposal
-                .votes_against
-                .checked_add(1)
-                .ok_or(Error::Overflow)?;
+        // dentro de la ventana de votacion?
+        let now = self.env().block_timestamp();
+        if now < proposal.start_time || now > proposal.end_time {
+            return Err(Error::VotingClosed);
+        }
+
+        // peso del votante
+        let weight = self.voting_power.get(caller).unwrap_or(1);
+        if weight < self.min_vote_power {
+            return Err(Error::InsufficientVotingPower);
         }
 
-        // inserts updates
-        self.proposals.insert(proposalid, &proposal);
-        self.has_voted.insert(key, &true);
+        let key = (proposalid, caller);
+        match self.has_voted.get(key) {
+            Some((previous, previous_weight)) => {
+                if previous == choice {
+                    return Err(Error::NoChange);
+                }
+
+                // cambios bloqueados cerca del cierre?
+                let locked_from = proposal.end_time.saturating_sub(proposal.lockout_ms);
+                if now >= locked_from {
+                    return Err(Error::VoteLocked);
+                }
+
+                // descontar el voto anterior con el peso que realmente se usó, y contar el nuevo
+                remove_vote_weight(&mut proposal, previous, previous_weight)?;
+                add_vote_weight(&mut proposal, choice, weight)?;
+
+                self.proposals.insert(proposalid, &proposal);
+                self.has_voted.insert(key, &(choice, weight));
+
+                self.env().emit_event(VoteChanged {
+                    proposalid,
+                    voter: caller,
+                    old_choice: previous,
+                    new_choice: choice,
+                });
+            }
+            None => {
+                add_vote_weight(&mut proposal, choice, weight)?;
+
+                self.proposals.insert(proposalid, &proposal);
+                self.has_voted.insert(key, &(choice, weight));
+
+                self.env().emit_event(VoteCast { proposalid, voter: caller, choice });
+            }
+        }
 
-        self.env().emit_event(VoteCast { proposalid, voter: caller, state });
         Ok(())
     }
 
+        /// Finalizes a proposal once its voting window has elapsed, computing whether it passed
+        /// against the configured `quorum` and `approval_numerator`/`approval_denominator`.
+        /// Can only be called once; further votes are rejected after finalization.
+        #[ink(message)]
+        pub fn finalize_proposal(&mut self, proposal_id: u32) -> Result<ProposalStatus, Error> {
+            let mut proposal = self.proposals.get(proposal_id).ok_or(Error::ProposalNotFound)?;
+
+            if proposal.status != ProposalStatus::Active {
+                return Err(Error::ProposalClosed);
+            }
+
+            let now = self.env().block_timestamp();
+            if now <= proposal.end_time {
+                return Err(Error::VotingStillOpen);
+            }
+
+            let total = proposal
+                .votes_for
+                .checked_add(proposal.votes_against)
+                .and_then(|sum| sum.checked_add(proposal.votes_abstain))
+                .ok_or(Error::Overflow)?;
+
+            let status = if total < self.quorum {
+                ProposalStatus::Rejected
+            } else {
+                let lhs = proposal
+                    .votes_for
+                    .checked_mul(self.approval_denominator)
+                    .ok_or(Error::Overflow)?;
+                let for_and_against = proposal
+                    .votes_for
+                    .checked_add(proposal.votes_against)
+                    .ok_or(Error::Overflow)?;
+                let rhs = for_and_against
+                    .checked_mul(self.approval_numerator)
+                    .ok_or(Error::Overflow)?;
+                if lhs > rhs {
+                    ProposalStatus::Passed
+                } else {
+                    ProposalStatus::Rejected
+                }
+            };
+
+            proposal.status = status;
+            self.proposals.insert(proposal_id, &proposal);
+            self.env().emit_event(ProposalFinalized { id: proposal_id, status });
+            Ok(status)
+        }
 
         /// Gets the proposal from the storage of the contract using the id
     #[ink(message)]
-        pub fn get_proposal(&self, proposal_id: u32) -> Result<(String, u32, u32), Error> {
+        pub fn get_proposal(&self, proposal_id: u32) -> Result<(String, u32, u32, u32, u64, u64, ProposalStatus), Error> {
             let proposal = self.proposals.get(proposal_id).ok_or(Error::ProposalNotFound)?;
-            Ok((proposal.title.clone(), proposal.votes_for, proposal.votes_against))
+            Ok((
+                proposal.title.clone(),
+                proposal.votes_for,
+                proposal.votes_against,
+                proposal.votes_abstain,
+                proposal.start_time,
+                proposal.end_time,
+                proposal.status,
+            ))
     }
 
         /// Returns the total number of proposals created
@@ -154,6 +409,26 @@ pub struct Proposal {
             self.next_proposal_id
         }
 
+        /// Returns a bounded page of proposals starting at `start_id`, each as
+        /// `(id, title, votes_for, votes_against)`. `limit` is clamped to `MAX_PAGE` so the call
+        /// stays within a predictable weight envelope no matter how many proposals exist; callers
+        /// walk the full set by paging with successive `start_id`s.
+        #[ink(message)]
+        pub fn get_proposals(&self, start_id: u32, limit: u32) -> Vec<(u32, String, u32, u32)> {
+            let limit = limit.min(MAX_PAGE);
+            let end = start_id.saturating_add(limit).min(self.next_proposal_id);
+
+            let mut page = Vec::new();
+            let mut id = start_id;
+            while id < end {
+                if let Some(proposal) = self.proposals.get(id) {
+                    page.push((id, proposal.title.clone(), proposal.votes_for, proposal.votes_against));
+                }
+                id += 1;
+            }
+            page
+        }
+
 
 
 
@@ -163,9 +438,20 @@ pub struct Proposal {
     /// In which the tested scenarios include:
     /// - Proposal creation by the owner
     /// - Proposal creation by a non-owner (should fail)
+    /// - Proposal creation with a duration below the minimum (should fail)
     /// - Voting on a proposal
-    /// - Voting twice on the same proposal (should fail)
+    /// - Abstaining on a proposal (counts toward quorum, not the for/against margin)
+    /// - Voting twice with the same choice (should fail)
+    /// - Changing a vote before the deadline (updates tallies, emits `VoteChanged`)
+    /// - Changing a vote within the per-proposal lockout window (should fail)
     /// - Voting on a non-existent proposal (should fail)
+    /// - Voting after the voting window has closed (should fail)
+    /// - Finalizing before the voting window has elapsed (should fail)
+    /// - Finalizing a proposal below quorum (rejected) and above it with a majority (passed)
+    /// - Voting on a proposal after it has been finalized (should fail)
+    /// - Voting with a configured voting power weight
+    /// - Voting below the configured minimum voting power (should fail)
+    /// - Paginated proposal listing, including limit clamping and out-of-range pages
     #[cfg(test)]
     mod tests {
             use super::*;
@@ -184,6 +470,10 @@ pub struct Proposal {
                 test::set_callee::<DefaultEnvironment>(callee);
             }
 
+            fn set_timestamp(timestamp: u64) {
+                test::set_block_timestamp::<DefaultEnvironment>(timestamp);
+            }
+
             #[test]
             fn owner_can_create_proposal() {
                 let accounts = default_accounts();
@@ -192,11 +482,14 @@ pub struct Proposal {
 
                 let mut contract = Votaciones::new();
 
-                let result = contract.create_proposal("Titulo".to_string());
+                let result = contract.create_proposal("Titulo".to_string(), 1_000, 0);
                 assert_eq!(result, Ok(0));
 
                 let proposal = contract.get_proposal(0).expect("proposal stored");
-                assert_eq!(proposal, ("Titulo".to_string(), 0, 0));
+                assert_eq!(
+                    proposal,
+                    ("Titulo".to_string(), 0, 0, 0, 0, 1_000, ProposalStatus::Active)
+                );
                 assert_eq!(contract.total_proposals(), 1);
 
                 let events: Vec<test::EmittedEvent> = test::recorded_events().collect();
@@ -205,6 +498,8 @@ pub struct Proposal {
                     .expect("decode event");
                 assert_eq!(decoded.id, 0);
                 assert_eq!(decoded.title, "Titulo");
+                assert_eq!(decoded.start_time, 0);
+                assert_eq!(decoded.end_time, 1_000);
             }
 
             #[test]
@@ -215,52 +510,159 @@ pub struct Proposal {
                 let mut contract = Votaciones::new();
 
                 set_caller(accounts.bob);
-                let result = contract.create_proposal("Tema".to_string());
+                let result = contract.create_proposal("Tema".to_string(), 1_000, 0);
                 assert_eq!(result, Err(Error::NotOwner));
             }
 
+            #[test]
+            fn duration_below_minimum_reverts() {
+                let accounts = default_accounts();
+                set_caller(accounts.alice);
+                set_callee(accounts.charlie);
+                let mut contract = Votaciones::new();
+                contract.set_min_duration(1_000).unwrap();
+
+                let result = contract.create_proposal("Tema".to_string(), 500, 0);
+                assert_eq!(result, Err(Error::DurationTooShort));
+            }
+
             #[test]
             fn vote_records_support() {
                 let accounts = default_accounts();
                 set_caller(accounts.alice);
                 set_callee(accounts.charlie);
                 let mut contract = Votaciones::new();
-                let proposal_id = contract.create_proposal("Tema".to_string()).unwrap();
+                let proposal_id = contract.create_proposal("Tema".to_string(), 1_000, 0).unwrap();
 
                 set_caller(accounts.bob);
-                let outcome = contract.vote(proposal_id, true);
+                let outcome = contract.vote(proposal_id, VoteChoice::For);
                 assert_eq!(outcome, Ok(()));
 
-                let (_, votes_for, votes_against) = contract.get_proposal(proposal_id).unwrap();
+                let (_, votes_for, votes_against, votes_abstain, _, _, _) =
+                    contract.get_proposal(proposal_id).unwrap();
                 assert_eq!(votes_for, 1);
                 assert_eq!(votes_against, 0);
+                assert_eq!(votes_abstain, 0);
 
                 let events: Vec<test::EmittedEvent> = test::recorded_events().collect();
                 let decoded = <VoteCast as scale::Decode>::decode(&mut &events.last().unwrap().data[..])
                     .expect("decode event");
                 assert_eq!(decoded.proposalid, proposal_id);
                 assert_eq!(decoded.voter, accounts.bob);
-                assert!(decoded.state);
+                assert_eq!(decoded.choice, VoteChoice::For);
+            }
+
+            #[test]
+            fn abstain_vote_counts_toward_quorum_only() {
+                let accounts = default_accounts();
+                set_caller(accounts.alice);
+                set_callee(accounts.charlie);
+                let mut contract = Votaciones::new();
+                let proposal_id = contract.create_proposal("Tema".to_string(), 1_000, 0).unwrap();
+
+                set_caller(accounts.bob);
+                let outcome = contract.vote(proposal_id, VoteChoice::Abstain);
+                assert_eq!(outcome, Ok(()));
+
+                let (_, votes_for, votes_against, votes_abstain, _, _, _) =
+                    contract.get_proposal(proposal_id).unwrap();
+                assert_eq!(votes_for, 0);
+                assert_eq!(votes_against, 0);
+                assert_eq!(votes_abstain, 1);
+
+                let second = contract.vote(proposal_id, VoteChoice::For);
+                assert_eq!(second, Ok(()));
+
+                let (_, votes_for, _votes_against, votes_abstain, _, _, _) =
+                    contract.get_proposal(proposal_id).unwrap();
+                assert_eq!(votes_for, 1);
+                assert_eq!(votes_abstain, 0);
             }
 
             #[test]
-            fn voting_twice_reverts() {
+            fn voting_twice_with_same_choice_reverts() {
                 let accounts = default_accounts();
                 set_caller(accounts.alice);
                 set_callee(accounts.charlie);
                 let mut contract = Votaciones::new();
-                let proposal_id = contract.create_proposal("Tema".to_string()).unwrap();
+                let proposal_id = contract.create_proposal("Tema".to_string(), 1_000, 0).unwrap();
 
                 set_caller(accounts.bob);
-                assert_eq!(contract.vote(proposal_id, true), Ok(()));
-                let second = contract.vote(proposal_id, true);
-                assert_eq!(second, Err(Error::AlreadyVoted));
+                assert_eq!(contract.vote(proposal_id, VoteChoice::For), Ok(()));
+                let second = contract.vote(proposal_id, VoteChoice::For);
+                assert_eq!(second, Err(Error::NoChange));
 
-                let (_, votes_for, votes_against) = contract.get_proposal(proposal_id).unwrap();
+                let (_, votes_for, votes_against, _, _, _, _) = contract.get_proposal(proposal_id).unwrap();
                 assert_eq!(votes_for, 1);
                 assert_eq!(votes_against, 0);
             }
 
+            #[test]
+            fn changing_vote_updates_tallies() {
+                let accounts = default_accounts();
+                set_caller(accounts.alice);
+                set_callee(accounts.charlie);
+                let mut contract = Votaciones::new();
+                let proposal_id = contract.create_proposal("Tema".to_string(), 1_000, 0).unwrap();
+
+                set_caller(accounts.bob);
+                assert_eq!(contract.vote(proposal_id, VoteChoice::For), Ok(()));
+                let outcome = contract.vote(proposal_id, VoteChoice::Against);
+                assert_eq!(outcome, Ok(()));
+
+                let (_, votes_for, votes_against, _, _, _, _) = contract.get_proposal(proposal_id).unwrap();
+                assert_eq!(votes_for, 0);
+                assert_eq!(votes_against, 1);
+
+                let events: Vec<test::EmittedEvent> = test::recorded_events().collect();
+                let decoded = <VoteChanged as scale::Decode>::decode(&mut &events.last().unwrap().data[..])
+                    .expect("decode event");
+                assert_eq!(decoded.proposalid, proposal_id);
+                assert_eq!(decoded.voter, accounts.bob);
+                assert_eq!(decoded.old_choice, VoteChoice::For);
+                assert_eq!(decoded.new_choice, VoteChoice::Against);
+            }
+
+            #[test]
+            fn changing_vote_within_lockout_reverts() {
+                let accounts = default_accounts();
+                set_caller(accounts.alice);
+                set_callee(accounts.charlie);
+                let mut contract = Votaciones::new();
+                let proposal_id = contract.create_proposal("Tema".to_string(), 1_000, 100).unwrap();
+
+                set_caller(accounts.bob);
+                assert_eq!(contract.vote(proposal_id, VoteChoice::For), Ok(()));
+
+                set_timestamp(901);
+                let outcome = contract.vote(proposal_id, VoteChoice::Against);
+                assert_eq!(outcome, Err(Error::VoteLocked));
+            }
+
+            #[test]
+            fn changing_vote_removes_the_old_tally_at_its_recorded_weight_and_adds_the_new_tally_at_current_weight() {
+                let accounts = default_accounts();
+                set_caller(accounts.alice);
+                set_callee(accounts.charlie);
+                let mut contract = Votaciones::new();
+                contract.set_voting_power(accounts.bob, 5).unwrap();
+                let proposal_id = contract.create_proposal("Tema".to_string(), 1_000, 0).unwrap();
+
+                set_caller(accounts.bob);
+                assert_eq!(contract.vote(proposal_id, VoteChoice::For), Ok(()));
+
+                // the owner re-weights bob after he already voted
+                set_caller(accounts.alice);
+                contract.set_voting_power(accounts.bob, 1).unwrap();
+
+                set_caller(accounts.bob);
+                assert_eq!(contract.vote(proposal_id, VoteChoice::Against), Ok(()));
+
+                let (_, votes_for, votes_against, _, _, _, _) = contract.get_proposal(proposal_id).unwrap();
+                assert_eq!(votes_for, 0);
+                assert_eq!(votes_against, 1);
+            }
+
             #[test]
             fn voting_nonexistent_reverts() {
                 let accounts = default_accounts();
@@ -269,10 +671,169 @@ pub struct Proposal {
                 let mut contract = Votaciones::new();
 
                 set_caller(accounts.bob);
-                let result = contract.vote(42, true);
+                let result = contract.vote(42, VoteChoice::For);
                 assert_eq!(result, Err(Error::ProposalNotFound));
             }
 
+            #[test]
+            fn voting_after_window_closes_reverts() {
+                let accounts = default_accounts();
+                set_caller(accounts.alice);
+                set_callee(accounts.charlie);
+                let mut contract = Votaciones::new();
+                let proposal_id = contract.create_proposal("Tema".to_string(), 1_000, 0).unwrap();
+
+                set_timestamp(1_001);
+                set_caller(accounts.bob);
+                let result = contract.vote(proposal_id, VoteChoice::For);
+                assert_eq!(result, Err(Error::VotingClosed));
+            }
+
+            #[test]
+            fn finalize_before_window_elapsed_reverts() {
+                let accounts = default_accounts();
+                set_caller(accounts.alice);
+                set_callee(accounts.charlie);
+                let mut contract = Votaciones::new();
+                let proposal_id = contract.create_proposal("Tema".to_string(), 1_000, 0).unwrap();
+
+                let result = contract.finalize_proposal(proposal_id);
+                assert_eq!(result, Err(Error::VotingStillOpen));
+            }
+
+            #[test]
+            fn finalize_rejects_below_quorum() {
+                let accounts = default_accounts();
+                set_caller(accounts.alice);
+                set_callee(accounts.charlie);
+                let mut contract = Votaciones::new();
+                contract.set_quorum(2).unwrap();
+                let proposal_id = contract.create_proposal("Tema".to_string(), 1_000, 0).unwrap();
+
+                set_caller(accounts.bob);
+                contract.vote(proposal_id, VoteChoice::For).unwrap();
+
+                set_timestamp(1_001);
+                let result = contract.finalize_proposal(proposal_id);
+                assert_eq!(result, Ok(ProposalStatus::Rejected));
+
+                let (.., status) = contract.get_proposal(proposal_id).unwrap();
+                assert_eq!(status, ProposalStatus::Rejected);
+            }
+
+            #[test]
+            fn finalize_passes_above_quorum_and_majority() {
+                let accounts = default_accounts();
+                set_caller(accounts.alice);
+                set_callee(accounts.charlie);
+                let mut contract = Votaciones::new();
+                contract.set_quorum(1).unwrap();
+                let proposal_id = contract.create_proposal("Tema".to_string(), 1_000, 0).unwrap();
+
+                set_caller(accounts.bob);
+                contract.vote(proposal_id, VoteChoice::For).unwrap();
+
+                set_timestamp(1_001);
+                let result = contract.finalize_proposal(proposal_id);
+                assert_eq!(result, Ok(ProposalStatus::Passed));
+
+                let events: Vec<test::EmittedEvent> = test::recorded_events().collect();
+                let decoded = <ProposalFinalized as scale::Decode>::decode(&mut &events.last().unwrap().data[..])
+                    .expect("decode event");
+                assert_eq!(decoded.id, proposal_id);
+                assert_eq!(decoded.status, ProposalStatus::Passed);
+            }
+
+            #[test]
+            fn votes_rejected_after_finalization() {
+                let accounts = default_accounts();
+                set_caller(accounts.alice);
+                set_callee(accounts.charlie);
+                let mut contract = Votaciones::new();
+                let proposal_id = contract.create_proposal("Tema".to_string(), 1_000, 0).unwrap();
+
+                set_timestamp(1_001);
+                contract.finalize_proposal(proposal_id).unwrap();
+
+                set_caller(accounts.bob);
+                let result = contract.vote(proposal_id, VoteChoice::For);
+                assert_eq!(result, Err(Error::ProposalClosed));
+            }
+
+            #[test]
+            fn vote_weight_adds_configured_power() {
+                let accounts = default_accounts();
+                set_caller(accounts.alice);
+                set_callee(accounts.charlie);
+                let mut contract = Votaciones::new();
+                contract.set_voting_power(accounts.bob, 5).unwrap();
+                let proposal_id = contract.create_proposal("Tema".to_string(), 1_000, 0).unwrap();
+
+                set_caller(accounts.bob);
+                assert_eq!(contract.vote(proposal_id, VoteChoice::For), Ok(()));
+
+                let (_, votes_for, votes_against, _, _, _, _) = contract.get_proposal(proposal_id).unwrap();
+                assert_eq!(votes_for, 5);
+                assert_eq!(votes_against, 0);
+            }
+
+            #[test]
+            fn vote_below_min_power_reverts() {
+                let accounts = default_accounts();
+                set_caller(accounts.alice);
+                set_callee(accounts.charlie);
+                let mut contract = Votaciones::new();
+                contract.set_min_vote_power(2).unwrap();
+                let proposal_id = contract.create_proposal("Tema".to_string(), 1_000, 0).unwrap();
+
+                set_caller(accounts.bob);
+                let result = contract.vote(proposal_id, VoteChoice::For);
+                assert_eq!(result, Err(Error::InsufficientVotingPower));
+            }
+
+            #[test]
+            fn get_proposals_returns_requested_page() {
+                let accounts = default_accounts();
+                set_caller(accounts.alice);
+                set_callee(accounts.charlie);
+                let mut contract = Votaciones::new();
+                contract.create_proposal("Uno".to_string(), 1_000, 0).unwrap();
+                contract.create_proposal("Dos".to_string(), 1_000, 0).unwrap();
+                contract.create_proposal("Tres".to_string(), 1_000, 0).unwrap();
+
+                let page = contract.get_proposals(1, 2);
+                assert_eq!(
+                    page,
+                    vec![(1, "Dos".to_string(), 0, 0), (2, "Tres".to_string(), 0, 0)]
+                );
+            }
+
+            #[test]
+            fn get_proposals_clamps_limit_to_max_page() {
+                let accounts = default_accounts();
+                set_caller(accounts.alice);
+                set_callee(accounts.charlie);
+                let mut contract = Votaciones::new();
+                for _ in 0..MAX_PAGE + 1 {
+                    contract.create_proposal("Uno".to_string(), 1_000, 0).unwrap();
+                }
+
+                let page = contract.get_proposals(0, MAX_PAGE + 1_000);
+                assert_eq!(page.len(), MAX_PAGE as usize);
+            }
+
+            #[test]
+            fn get_proposals_past_end_returns_empty() {
+                let accounts = default_accounts();
+                set_caller(accounts.alice);
+                set_callee(accounts.charlie);
+                let mut contract = Votaciones::new();
+                contract.create_proposal("Uno".to_string(), 1_000, 0).unwrap();
+
+                let page = contract.get_proposals(5, 10);
+                assert!(page.is_empty());
+            }
+
     }
 
 
@@ -298,13 +859,13 @@ pub struct Proposal {
 
             let mut contract_ref = contract.call_builder::<Votaciones>();
 
-            let create = contract_ref.create_proposal("Tema".into());
+            let create = contract_ref.create_proposal("Tema".into(), 60_000, 0);
             let mut create_call = client.call(&alice, &create);
             let create_outcome = create_call.submit().await?;
             let proposal_id = create_outcome.return_value();
             assert_eq!(proposal_id, Ok(0));
 
-            let vote = contract_ref.vote(0, true);
+            let vote = contract_ref.vote(0, VoteChoice::For);
             let mut vote_call = client.call(&bob, &vote);
             let vote_outcome = vote_call.submit().await?;
             assert_eq!(vote_outcome.return_value(), Ok(()));
@@ -312,7 +873,7 @@ pub struct Proposal {
             let get = contract_ref.get_proposal(0);
             let mut get_call = client.call(&alice, &get);
             let get_outcome = get_call.dry_run().await?;
-            let (title, votes_for, votes_against) = get_outcome.return_value().unwrap();
+            let (title, votes_for, votes_against, _, _, _, _) = get_outcome.return_value().unwrap();
             assert_eq!(title, "Tema");
             assert_eq!(votes_for, 1);
             assert_eq!(votes_against, 0);
@@ -321,7 +882,9 @@ pub struct Proposal {
         }
 
         #[ink_e2e::test]
-        async fn e2e_double_vote_reverts(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+        async fn e2e_repeat_vote_with_same_choice_reverts(
+            mut client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
             let alice = ink_e2e::alice();
             let bob = ink_e2e::bob();
 
@@ -333,17 +896,55 @@ pub struct Proposal {
 
             let mut contract_ref = contract.call_builder::<Votaciones>();
 
-            let create = contract_ref.create_proposal("Tema".into());
+            let create = contract_ref.create_proposal("Tema".into(), 60_000, 0);
             let mut create_call = client.call(&alice, &create);
             create_call.submit().await?;
 
-            let vote = contract_ref.vote(0, true);
+            let vote = contract_ref.vote(0, VoteChoice::For);
             let mut vote_call = client.call(&bob, &vote);
             vote_call.submit().await?;
 
             let mut second_call = client.call(&bob, &vote);
             let second_vote = second_call.dry_run().await?.return_value();
-            assert_eq!(second_vote, Err(Error::AlreadyVoted));
+            assert_eq!(second_vote, Err(Error::NoChange));
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn e2e_changing_vote_updates_tallies(
+            mut client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let alice = ink_e2e::alice();
+            let bob = ink_e2e::bob();
+
+            let mut constructor = VotacionesRef::new();
+            let contract = client
+                .instantiate("votaciones", &alice, &mut constructor)
+                .submit()
+                .await?;
+
+            let mut contract_ref = contract.call_builder::<Votaciones>();
+
+            let create = contract_ref.create_proposal("Tema".into(), 60_000, 0);
+            let mut create_call = client.call(&alice, &create);
+            create_call.submit().await?;
+
+            let vote = contract_ref.vote(0, VoteChoice::For);
+            let mut vote_call = client.call(&bob, &vote);
+            vote_call.submit().await?;
+
+            let change = contract_ref.vote(0, VoteChoice::Against);
+            let mut change_call = client.call(&bob, &change);
+            let change_outcome = change_call.submit().await?;
+            assert_eq!(change_outcome.return_value(), Ok(()));
+
+            let get = contract_ref.get_proposal(0);
+            let mut get_call = client.call(&alice, &get);
+            let get_outcome = get_call.dry_run().await?;
+            let (_, votes_for, votes_against, _, _, _, _) = get_outcome.return_value().unwrap();
+            assert_eq!(votes_for, 0);
+            assert_eq!(votes_against, 1);
 
             Ok(())
         }
@@ -361,7 +962,7 @@ pub struct Proposal {
 
             let mut contract_ref = contract.call_builder::<Votaciones>();
 
-            let vote = contract_ref.vote(99, true);
+            let vote = contract_ref.vote(99, VoteChoice::For);
             let mut vote_call = client.call(&bob, &vote);
             let result = vote_call.dry_run().await?.return_value();
             assert_eq!(result, Err(Error::ProposalNotFound));